@@ -6,18 +6,24 @@
 
 use core::fmt;
 use core::mem::ManuallyDrop;
+use core::ops;
 use core::ptr::NonNull;
 
 /// An intrusive linked list.
 ///
 /// Currently, the list is not emptied on drop. It is the caller's
-/// responsibility to ensure the list is empty before dropping it.
+/// responsibility to ensure the list is empty before dropping it, either by
+/// calling [`LinkedList::clear`] or by wrapping the list in
+/// [`DrainOnDrop`] when it owns its handles.
 pub(crate) struct LinkedList<T: Link> {
     /// Linked list head
     head: Option<NonNull<T::Target>>,
 
     /// Linked list tail
     tail: Option<NonNull<T::Target>>,
+
+    /// Number of nodes currently in the list
+    len: usize,
 }
 
 unsafe impl<T: Link> Send for LinkedList<T> where T::Target: Send {}
@@ -72,6 +78,7 @@ impl<T: Link> LinkedList<T> {
         LinkedList {
             head: None,
             tail: None,
+            len: 0,
         }
     }
 
@@ -94,6 +101,32 @@ impl<T: Link> LinkedList<T> {
             if self.tail.is_none() {
                 self.tail = Some(ptr);
             }
+
+            self.len += 1;
+        }
+    }
+
+    /// Adds an element last in the list.
+    pub(crate) fn push_back(&mut self, val: T::Handle) {
+        // The value should not be dropped, it is being inserted into the list
+        let val = ManuallyDrop::new(val);
+        let ptr = T::as_raw(&*val);
+        assert_ne!(self.tail, Some(ptr));
+        unsafe {
+            T::pointers(ptr).as_mut().next = None;
+            T::pointers(ptr).as_mut().prev = self.tail;
+
+            if let Some(tail) = self.tail {
+                T::pointers(tail).as_mut().next = Some(ptr);
+            }
+
+            self.tail = Some(ptr);
+
+            if self.head.is_none() {
+                self.head = Some(ptr);
+            }
+
+            self.len += 1;
         }
     }
 
@@ -113,10 +146,34 @@ impl<T: Link> LinkedList<T> {
             T::pointers(last).as_mut().prev = None;
             T::pointers(last).as_mut().next = None;
 
+            self.len -= 1;
+
             Some(T::from_raw(last))
         }
     }
 
+    /// Removes the first element from a list and returns it, or None if it
+    /// is empty.
+    pub(crate) fn pop_front(&mut self) -> Option<T::Handle> {
+        unsafe {
+            let first = self.head?;
+            self.head = T::pointers(first).as_ref().next;
+
+            if let Some(next) = T::pointers(first).as_ref().next {
+                T::pointers(next).as_mut().prev = None;
+            } else {
+                self.tail = None
+            }
+
+            T::pointers(first).as_mut().prev = None;
+            T::pointers(first).as_mut().next = None;
+
+            self.len -= 1;
+
+            Some(T::from_raw(first))
+        }
+    }
+
     /// Returns whether the linked list doesn not contain any node
     pub(crate) fn is_empty(&self) -> bool {
         if self.head.is_some() {
@@ -127,6 +184,14 @@ impl<T: Link> LinkedList<T> {
         true
     }
 
+    /// Returns the number of nodes currently stored in the list.
+    ///
+    /// This is tracked incrementally, so it is `O(1)` instead of walking the
+    /// list.
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
     /// Removes the specified node from the list
     ///
     /// # Safety
@@ -160,8 +225,23 @@ impl<T: Link> LinkedList<T> {
         T::pointers(node).as_mut().next = None;
         T::pointers(node).as_mut().prev = None;
 
+        self.len -= 1;
+
         Some(T::from_raw(node))
     }
+
+    /// Removes every node from the list, dropping each returned handle.
+    ///
+    /// This unlinks every node currently in the list and drops the handle
+    /// returned for it. Where the handle owns its node (for example a
+    /// `Pin<Box<_>>`), this runs the node's destructor; borrowed handles
+    /// leave their nodes alive. It is the caller's responsibility to call
+    /// this (or wrap the list in [`DrainOnDrop`]) when the list owns its
+    /// handles; `LinkedList` itself is not emptied on drop, since many
+    /// intrusive uses hold only borrowed nodes.
+    pub(crate) fn clear(&mut self) {
+        while self.pop_back().is_some() {}
+    }
 }
 
 cfg_sync! {
@@ -188,6 +268,16 @@ cfg_sync! {
         /// The caller **must** ensure that `node` is currently contained by
         /// `self` or not contained by any other list.
         pub(crate) unsafe fn split_back(&mut self, node: NonNull<T::Target>) -> Self {
+            // Count how many nodes, starting at `node` and following `next`
+            // pointers to the tail, are moving into the new list. This has
+            // to happen before any pointers are severed below.
+            let mut moved = 1;
+            let mut curr = node;
+            while let Some(next) = T::pointers(curr).as_ref().next {
+                moved += 1;
+                curr = next;
+            }
+
             let new_tail = T::pointers(node).as_mut().prev.take().map(|prev| {
                 T::pointers(prev).as_mut().next = None;
                 prev
@@ -196,9 +286,13 @@ cfg_sync! {
                 self.head = None;
             }
             let tail = std::mem::replace(&mut self.tail, new_tail);
+
+            self.len -= moved;
+
             Self {
                 head: Some(node),
                 tail,
+                len: moved,
             }
         }
 
@@ -209,6 +303,59 @@ cfg_sync! {
             Self {
                 head: self.head.take(),
                 tail: self.tail.take(),
+                len: std::mem::replace(&mut self.len, 0),
+            }
+        }
+
+        /// Moves all entries from `other` onto the back of `self`, leaving
+        /// `other` empty.
+        ///
+        /// This is the inverse of `split_back`/`take_all`: where those pull
+        /// nodes out of a list, `append` joins two lists back together in
+        /// `O(1)`.
+        pub(crate) fn append(&mut self, other: &mut Self) {
+            let other_head = match other.head.take() {
+                Some(head) => head,
+                None => return,
+            };
+            let other_tail = other.tail.take().unwrap();
+
+            unsafe {
+                T::pointers(other_head).as_mut().prev = self.tail;
+            }
+
+            if let Some(tail) = self.tail {
+                unsafe {
+                    T::pointers(tail).as_mut().next = Some(other_head);
+                }
+            } else {
+                self.head = Some(other_head);
+            }
+
+            self.tail = Some(other_tail);
+            self.len += other.len;
+            other.len = 0;
+        }
+
+        /// Returns a cursor positioned at the front of the list.
+        ///
+        /// The cursor starts pointing at the head node (or at the "ghost"
+        /// null position if the list is empty).
+        pub(crate) fn cursor_front(&mut self) -> CursorMut<'_, T> {
+            CursorMut {
+                curr: self.head,
+                list: self,
+            }
+        }
+
+        /// Returns a cursor positioned at the back of the list.
+        ///
+        /// The cursor starts pointing at the tail node (or at the "ghost"
+        /// null position if the list is empty).
+        pub(crate) fn cursor_back(&mut self) -> CursorMut<'_, T> {
+            CursorMut {
+                curr: self.tail,
+                list: self,
             }
         }
     }
@@ -219,10 +366,50 @@ impl<T: Link> fmt::Debug for LinkedList<T> {
         f.debug_struct("LinkedList")
             .field("head", &self.head)
             .field("tail", &self.tail)
+            .field("len", &self.len)
             .finish()
     }
 }
 
+// ===== impl DrainOnDrop =====
+
+/// Wraps a [`LinkedList`] so that it is emptied via [`LinkedList::clear`]
+/// when the guard is dropped.
+///
+/// `LinkedList` does not clear itself on drop, because many intrusive uses
+/// only ever link borrowed nodes, and running their destructors here would
+/// be unsound or simply wrong. Opt in to this guard only when the list is
+/// the sole owner of its handles (for example a list of `Pin<Box<_>>`
+/// nodes), so that leaking the remaining entries doesn't leak memory.
+pub(crate) struct DrainOnDrop<T: Link>(LinkedList<T>);
+
+impl<T: Link> DrainOnDrop<T> {
+    /// Wraps `list` so that it is drained when the guard is dropped.
+    pub(crate) fn new(list: LinkedList<T>) -> Self {
+        Self(list)
+    }
+}
+
+impl<T: Link> ops::Deref for DrainOnDrop<T> {
+    type Target = LinkedList<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Link> ops::DerefMut for DrainOnDrop<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Link> Drop for DrainOnDrop<T> {
+    fn drop(&mut self) {
+        self.0.clear();
+    }
+}
+
 // ===== impl Iter =====
 
 #[cfg(any(feature = "sync", feature = "rt-threaded"))]
@@ -273,6 +460,108 @@ cfg_sync! {
     }
 }
 
+cfg_sync! {
+    // ===== impl CursorMut =====
+
+    /// A cursor over a `LinkedList` which holds the list mutably borrowed and
+    /// allows in-place removal of the node the cursor points at. Nodes are
+    /// only ever handed out as shared references.
+    ///
+    /// The cursor always points at some node in the list, or at the "ghost"
+    /// null position between the tail and the head, which is useful as a
+    /// loop sentinel: moving past either end of the list lands the cursor
+    /// on the ghost position, and moving again from there wraps around to
+    /// the opposite end.
+    pub(crate) struct CursorMut<'a, T: Link> {
+        list: &'a mut LinkedList<T>,
+        curr: Option<NonNull<T::Target>>,
+    }
+
+    impl<'a, T: Link> CursorMut<'a, T> {
+        /// Returns a reference to the node the cursor currently points at.
+        ///
+        /// Returns `None` if the cursor is currently at the ghost position.
+        pub(crate) fn current(&self) -> Option<&T::Target> {
+            let curr = self.curr?;
+            // safety: the cursor's `curr` pointer always refers to a node
+            // still owned by `self.list`.
+            Some(unsafe { &*curr.as_ptr() })
+        }
+
+        /// Returns a reference to the next node without moving the cursor.
+        pub(crate) fn peek_next(&self) -> Option<&T::Target> {
+            let next = match self.curr {
+                Some(curr) => unsafe { T::pointers(curr).as_ref() }.next,
+                None => self.list.head,
+            };
+
+            next.map(|curr| unsafe { &*curr.as_ptr() })
+        }
+
+        /// Returns a reference to the previous node without moving the
+        /// cursor.
+        pub(crate) fn peek_prev(&self) -> Option<&T::Target> {
+            let prev = match self.curr {
+                Some(curr) => unsafe { T::pointers(curr).as_ref() }.prev,
+                None => self.list.tail,
+            };
+
+            prev.map(|curr| unsafe { &*curr.as_ptr() })
+        }
+
+        /// Moves the cursor to the next node.
+        ///
+        /// If the cursor is currently at the tail, it moves to the ghost
+        /// position. If it is at the ghost position, it moves to the head.
+        pub(crate) fn move_next(&mut self) {
+            match self.curr.take() {
+                Some(curr) => {
+                    self.curr = unsafe { T::pointers(curr).as_ref() }.next;
+                }
+                None => {
+                    self.curr = self.list.head;
+                }
+            }
+        }
+
+        /// Moves the cursor to the previous node.
+        ///
+        /// If the cursor is currently at the head, it moves to the ghost
+        /// position. If it is at the ghost position, it moves to the tail.
+        pub(crate) fn move_prev(&mut self) {
+            match self.curr.take() {
+                Some(curr) => {
+                    self.curr = unsafe { T::pointers(curr).as_ref() }.prev;
+                }
+                None => {
+                    self.curr = self.list.tail;
+                }
+            }
+        }
+
+        /// Removes the node the cursor is currently pointing at and
+        /// advances the cursor to the node that followed it.
+        ///
+        /// Returns `None` if the cursor is at the ghost position.
+        pub(crate) fn remove_current(&mut self) -> Option<T::Handle> {
+            let curr = self.curr?;
+
+            // safety: `curr` is still linked into `self.list`, so reading
+            // its `next` pointer before unlinking it is valid.
+            let next = unsafe { T::pointers(curr).as_ref() }.next;
+
+            // safety: the cursor only ever takes its position from
+            // `self.list`'s head/tail or from the links of its nodes, and
+            // holds the list mutably borrowed, so `curr` belongs to
+            // `self.list`.
+            let handle = unsafe { self.list.remove(curr) };
+
+            self.curr = next;
+            handle
+        }
+    }
+}
+
 // ===== impl Pointers =====
 
 impl<T> Pointers<T> {
@@ -402,6 +691,36 @@ mod tests {
         assert!(list.is_empty());
     }
 
+    #[test]
+    fn len() {
+        let a = entry(5);
+        let b = entry(7);
+        let c = entry(31);
+
+        let mut list = LinkedList::<&Entry>::new();
+        assert_eq!(0, list.len());
+
+        list.push_front(a.as_ref());
+        assert_eq!(1, list.len());
+        list.push_back(b.as_ref());
+        assert_eq!(2, list.len());
+        list.push_front(c.as_ref());
+        assert_eq!(3, list.len());
+
+        assert!(list.pop_back().is_some());
+        assert_eq!(2, list.len());
+        assert!(list.pop_front().is_some());
+        assert_eq!(1, list.len());
+
+        unsafe {
+            assert!(list.remove(ptr(&c)).is_none());
+            assert_eq!(1, list.len());
+        }
+
+        assert!(list.pop_back().is_some());
+        assert_eq!(0, list.len());
+    }
+
     #[test]
     fn push_pop_push_pop() {
         let a = entry(5);
@@ -424,6 +743,46 @@ mod tests {
         assert!(list.pop_back().is_none());
     }
 
+    #[test]
+    fn push_back_pop_front() {
+        let a = entry(5);
+        let b = entry(7);
+        let c = entry(31);
+
+        let mut list = LinkedList::<&Entry>::new();
+        assert!(list.is_empty());
+
+        list.push_back(a.as_ref());
+        assert!(!list.is_empty());
+        list.push_back(b.as_ref());
+        list.push_back(c.as_ref());
+
+        assert_eq!(5, list.pop_front().unwrap().val);
+        assert_eq!(7, list.pop_front().unwrap().val);
+        assert_eq!(31, list.pop_front().unwrap().val);
+
+        assert!(list.is_empty());
+        assert!(list.pop_front().is_none());
+    }
+
+    #[test]
+    fn mixed_push_pop_ends() {
+        let a = entry(1);
+        let b = entry(2);
+        let c = entry(3);
+
+        let mut list = LinkedList::<&Entry>::new();
+        list.push_back(a.as_ref());
+        list.push_front(b.as_ref());
+        list.push_back(c.as_ref());
+
+        // list is now [2, 1, 3]
+        assert_eq!(2, list.pop_front().unwrap().val);
+        assert_eq!(3, list.pop_back().unwrap().val);
+        assert_eq!(1, list.pop_front().unwrap().val);
+        assert!(list.is_empty());
+    }
+
     #[test]
     fn remove_by_address() {
         let a = entry(5);
@@ -607,6 +966,9 @@ mod tests {
             );
             let mut list2 = unsafe { list1.split_back(ptr(&a)) };
 
+            assert_eq!(3, list1.len());
+            assert_eq!(1, list2.len());
+
             assert_eq!([2, 3, 4].to_vec(), collect_list(&mut list1));
             assert_eq!([1].to_vec(), collect_list(&mut list2));
         }
@@ -661,16 +1023,205 @@ mod tests {
         list1.push_front(b.as_ref());
 
         assert!(!list1.is_empty());
+        assert_eq!(2, list1.len());
 
         let mut list2 = list1.take_all();
 
         assert!(list1.is_empty());
+        assert_eq!(0, list1.len());
         assert!(!list2.is_empty());
+        assert_eq!(2, list2.len());
 
         assert_eq!(Vec::<i32>::new(), collect_list(&mut list1));
         assert_eq!([1, 2].to_vec(), collect_list(&mut list2));
     }
 
+    #[test]
+    fn append() {
+        let a = entry(1);
+        let b = entry(2);
+        let c = entry(3);
+        let d = entry(4);
+
+        // appending a non-empty list onto a non-empty list
+        {
+            let mut list1 = LinkedList::<&Entry>::new();
+            list1.push_back(a.as_ref());
+            list1.push_back(b.as_ref());
+
+            let mut list2 = LinkedList::<&Entry>::new();
+            list2.push_back(c.as_ref());
+            list2.push_back(d.as_ref());
+
+            list1.append(&mut list2);
+
+            assert_eq!(4, list1.len());
+            assert!(list2.is_empty());
+            assert_eq!(0, list2.len());
+
+            assert_eq!([4, 3, 2, 1].to_vec(), collect_list(&mut list1));
+        }
+
+        // appending an empty list is a no-op
+        {
+            let mut list1 = LinkedList::<&Entry>::new();
+            list1.push_back(a.as_ref());
+
+            let mut list2 = LinkedList::<&Entry>::new();
+
+            list1.append(&mut list2);
+
+            assert_eq!(1, list1.len());
+            assert_eq!([1].to_vec(), collect_list(&mut list1));
+        }
+
+        // appending onto an empty list
+        {
+            let mut list1 = LinkedList::<&Entry>::new();
+
+            let mut list2 = LinkedList::<&Entry>::new();
+            list2.push_back(b.as_ref());
+
+            list1.append(&mut list2);
+
+            assert_eq!(1, list1.len());
+            assert!(list2.is_empty());
+            assert_eq!([2].to_vec(), collect_list(&mut list1));
+        }
+    }
+
+    #[test]
+    fn clear() {
+        let a = entry(1);
+        let b = entry(2);
+        let c = entry(3);
+
+        let mut list = LinkedList::<&Entry>::new();
+        push_all(&mut list, &[c.as_ref(), b.as_ref(), a.as_ref()]);
+        assert_eq!(3, list.len());
+
+        list.clear();
+
+        assert!(list.is_empty());
+        assert_eq!(0, list.len());
+        assert_clean!(a);
+        assert_clean!(b);
+        assert_clean!(c);
+    }
+
+    #[test]
+    fn drain_on_drop() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        struct DropEntry {
+            pointers: Pointers<DropEntry>,
+            dropped: Rc<Cell<usize>>,
+        }
+
+        impl Drop for DropEntry {
+            fn drop(&mut self) {
+                self.dropped.set(self.dropped.get() + 1);
+            }
+        }
+
+        unsafe impl Link for Box<DropEntry> {
+            type Handle = Pin<Box<DropEntry>>;
+            type Target = DropEntry;
+
+            fn as_raw(handle: &Pin<Box<DropEntry>>) -> NonNull<DropEntry> {
+                NonNull::from(handle.as_ref().get_ref())
+            }
+
+            unsafe fn from_raw(ptr: NonNull<DropEntry>) -> Pin<Box<DropEntry>> {
+                Pin::new_unchecked(Box::from_raw(ptr.as_ptr()))
+            }
+
+            unsafe fn pointers(mut target: NonNull<DropEntry>) -> NonNull<Pointers<DropEntry>> {
+                NonNull::from(&mut target.as_mut().pointers)
+            }
+        }
+
+        let dropped = Rc::new(Cell::new(0));
+
+        {
+            let mut list = DrainOnDrop::new(LinkedList::<Box<DropEntry>>::new());
+            for _ in 0..3 {
+                list.push_back(Box::pin(DropEntry {
+                    pointers: Pointers::new(),
+                    dropped: dropped.clone(),
+                }));
+            }
+            assert_eq!(3, list.len());
+            assert_eq!(0, dropped.get());
+        }
+
+        assert_eq!(3, dropped.get());
+    }
+
+    #[test]
+    fn cursor() {
+        let a = entry(1);
+        let b = entry(2);
+        let c = entry(3);
+
+        let mut list = LinkedList::<&Entry>::new();
+        push_all(&mut list, &[c.as_ref(), b.as_ref(), a.as_ref()]);
+
+        let mut cursor = list.cursor_front();
+        assert_eq!(1, cursor.current().unwrap().val);
+        assert_eq!(2, cursor.peek_next().unwrap().val);
+        assert!(cursor.peek_prev().is_none());
+
+        cursor.move_next();
+        assert_eq!(2, cursor.current().unwrap().val);
+
+        cursor.move_next();
+        assert_eq!(3, cursor.current().unwrap().val);
+
+        // moving past the tail lands on the ghost position
+        cursor.move_next();
+        assert!(cursor.current().is_none());
+
+        // moving again from the ghost position wraps to the head
+        cursor.move_next();
+        assert_eq!(1, cursor.current().unwrap().val);
+
+        let mut cursor = list.cursor_back();
+        assert_eq!(3, cursor.current().unwrap().val);
+        cursor.move_prev();
+        assert_eq!(2, cursor.current().unwrap().val);
+        cursor.move_prev();
+        assert_eq!(1, cursor.current().unwrap().val);
+        cursor.move_prev();
+        assert!(cursor.current().is_none());
+        cursor.move_prev();
+        assert_eq!(3, cursor.current().unwrap().val);
+    }
+
+    #[test]
+    fn cursor_remove_current() {
+        let a = entry(1);
+        let b = entry(2);
+        let c = entry(3);
+
+        let mut list = LinkedList::<&Entry>::new();
+        push_all(&mut list, &[c.as_ref(), b.as_ref(), a.as_ref()]);
+
+        let mut cursor = list.cursor_front();
+        cursor.move_next();
+
+        let removed = cursor.remove_current().unwrap();
+        assert_eq!(2, removed.val);
+        assert_clean!(b);
+
+        // the cursor now points at the node that followed the removed one
+        assert_eq!(3, cursor.current().unwrap().val);
+
+        let items = collect_list(&mut list);
+        assert_eq!([3, 1].to_vec(), items);
+    }
+
     proptest::proptest! {
         #[test]
         fn fuzz_linked_list(ops: Vec<usize>) {
@@ -735,6 +1286,8 @@ mod tests {
                     }
                 }
             }
+
+            assert_eq!(reference.len(), ll.len());
         }
     }
 }